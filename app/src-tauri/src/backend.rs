@@ -1,27 +1,145 @@
-use std::io::Write;
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Read, Write};
 use std::net::TcpStream;
 use std::path::PathBuf;
-use std::process::{Child, Command, Stdio};
+use std::process::{Child, ChildStderr, ChildStdout, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
 use std::time::Duration;
 
-use tauri::{AppHandle, Manager};
+use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager};
 
-use crate::paths::{get_keero_dir, get_docs_dir, get_images_dir, get_venv_python, get_voices_dir, get_tesseract_cmd};
+use crate::paths::{get_keero_dir, get_docs_dir, get_images_dir, get_venv_python, get_voices_dir, get_tesseract_cmd, sanitize_sandbox_env};
 
 pub struct ApiProcess(pub Mutex<Option<Child>>);
 
+/// Max number of backend log lines kept in memory for `get_backend_logs`.
+const MAX_LOG_LINES: usize = 500;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackendLogLine {
+    pub level: String,
+    pub line: String,
+    pub stream: String,
+    pub ts: u64,
+}
+
+#[derive(Default)]
+pub struct BackendLogBuffer(pub Mutex<VecDeque<BackendLogLine>>);
+
+fn now_unix_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Classifies a uvicorn/python log line by its leading level prefix, defaulting to info.
+fn classify_level(line: &str) -> &'static str {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with("ERROR:") || trimmed.starts_with("CRITICAL:") {
+        "error"
+    } else if trimmed.starts_with("WARNING:") {
+        "warn"
+    } else if trimmed.starts_with("DEBUG:") {
+        "debug"
+    } else {
+        "info"
+    }
+}
+
+fn push_backend_log(app: &AppHandle, entry: BackendLogLine) {
+    if let Some(buffer) = app.try_state::<BackendLogBuffer>() {
+        if let Ok(mut buf) = buffer.0.lock() {
+            buf.push_back(entry.clone());
+            while buf.len() > MAX_LOG_LINES {
+                buf.pop_front();
+            }
+        }
+    }
+    let _ = app.emit("backend-log", entry);
+}
+
+/// Reads a child stream line-by-line, logging and forwarding each line as a
+/// `backend-log` event until the stream closes (process exit or pipe drop).
+fn spawn_log_reader<R>(app: AppHandle, stream: &'static str, reader: R)
+where
+    R: Read + Send + 'static,
+{
+    std::thread::spawn(move || {
+        for line in BufReader::new(reader).lines().map_while(Result::ok) {
+            let level = classify_level(&line);
+            match level {
+                "error" => error!(target: "backend-proc", "{}", line),
+                "warn" => warn!(target: "backend-proc", "{}", line),
+                "debug" => debug!(target: "backend-proc", "{}", line),
+                _ => info!(target: "backend-proc", "{}", line),
+            }
+
+            push_backend_log(
+                &app,
+                BackendLogLine {
+                    level: level.to_string(),
+                    line,
+                    stream: stream.to_string(),
+                    ts: now_unix_secs(),
+                },
+            );
+        }
+    });
+}
+
+fn spawn_log_readers(app: &AppHandle, stdout: Option<ChildStdout>, stderr: Option<ChildStderr>) {
+    if let Some(stdout) = stdout {
+        spawn_log_reader(app.clone(), "stdout", stdout);
+    }
+    if let Some(stderr) = stderr {
+        spawn_log_reader(app.clone(), "stderr", stderr);
+    }
+}
+
+#[tauri::command]
+pub async fn get_backend_logs(app: AppHandle) -> Result<Vec<BackendLogLine>, String> {
+    let buffer = app.state::<BackendLogBuffer>();
+    let buf = buffer
+        .0
+        .lock()
+        .map_err(|_| "Backend log buffer lock poisoned".to_string())?;
+    Ok(buf.iter().cloned().collect())
+}
+
+/// Listening port for the backend, overridable so multiple instances or a
+/// busy default port don't collide. Re-read on every use rather than cached,
+/// since it only ever changes between process launches.
+fn backend_port() -> u16 {
+    std::env::var("PIXM8_API_PORT")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(8000)
+}
+
+/// Set by `stop_api_server` to tell a running supervisor loop to exit instead
+/// of treating the deliberate shutdown as a crash to restart from.
+#[derive(Default)]
+pub struct SupervisorControl(pub AtomicBool);
+
+/// How often the supervisor checks whether the tracked child has exited.
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+const BASE_BACKOFF_MS: u64 = 500;
+const MAX_BACKOFF_MS: u64 = 30_000;
+const MAX_RESTART_ATTEMPTS: u32 = 6;
+
 pub fn ensure_port_free(port: u16) {
     let addr = ("127.0.0.1", port);
 
     if TcpStream::connect(addr).is_ok() {
-        if port == 8000 {
-            let _ = TcpStream::connect(addr).and_then(|mut stream| {
-                let req = b"POST /shutdown HTTP/1.1\r\nHost: 127.0.0.1\r\nContent-Length: 0\r\n\r\n";
-                stream.write_all(req)
-            });
-            std::thread::sleep(Duration::from_millis(500));
-        }
+        let _ = TcpStream::connect(addr).and_then(|mut stream| {
+            let req = b"POST /shutdown HTTP/1.1\r\nHost: 127.0.0.1\r\nContent-Length: 0\r\n\r\n";
+            stream.write_all(req)
+        });
+        std::thread::sleep(Duration::from_millis(500));
 
         if cfg!(unix) {
             let _ = Command::new("sh")
@@ -40,7 +158,12 @@ pub fn ensure_port_free(port: u16) {
 }
 
 pub fn stop_api_server(app: &tauri::AppHandle) {
-    let _ = TcpStream::connect(("127.0.0.1", 8000)).and_then(|mut stream| {
+    if let Some(control) = app.try_state::<SupervisorControl>() {
+        control.0.store(true, Ordering::SeqCst);
+    }
+
+    let port = backend_port();
+    let _ = TcpStream::connect(("127.0.0.1", port)).and_then(|mut stream| {
         let req = b"POST /shutdown HTTP/1.1\r\nHost: 127.0.0.1\r\nContent-Length: 0\r\n\r\n";
         stream.write_all(req)
     });
@@ -58,47 +181,56 @@ pub fn stop_api_server(app: &tauri::AppHandle) {
     if cfg!(unix) {
         let _ = Command::new("sh")
             .arg("-c")
-            .arg("lsof -ti:8000 | xargs kill -9")
+            .arg(format!("lsof -ti:{port} | xargs kill -9"))
             .output();
     }
 }
 
-#[tauri::command]
-pub async fn start_backend(app: AppHandle) -> Result<String, String> {
-    if TcpStream::connect_timeout(&"127.0.0.1:8000".parse().unwrap(), Duration::from_millis(100))
-        .is_ok()
-    {
-        return Ok("Backend already running".to_string());
+/// Stores `child` in the already-managed `ApiProcess`, or manages it for the
+/// first time if this is the very first backend start. `AppHandle::manage`
+/// is a no-op past the first call for a given type, so a restart must update
+/// the existing state in place rather than trying to re-manage it.
+fn install_api_process(app: &AppHandle, child: Child) {
+    if let Some(state) = app.try_state::<ApiProcess>() {
+        if let Ok(mut guard) = state.0.lock() {
+            *guard = Some(child);
+            return;
+        }
     }
+    app.manage(ApiProcess(Mutex::new(Some(child))));
+}
 
-    let venv_python = get_venv_python(&app);
+fn resolve_python_dir(app: &AppHandle) -> PathBuf {
+    let resource_dir = app.path().resource_dir().ok();
+    let bundled_path = resource_dir.as_ref().map(|r| r.join("python-backend"));
+    if bundled_path.as_ref().map(|p| p.exists()).unwrap_or(false) {
+        bundled_path.unwrap()
+    } else {
+        let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        manifest_dir
+            .parent()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .join("resources")
+            .join("python-backend")
+    }
+}
+
+/// Builds and spawns the uvicorn child with piped stdio and log readers
+/// wired up. Shared by the initial start and every supervisor-driven restart
+/// so they can never drift out of sync with each other.
+fn spawn_backend(app: &AppHandle, port: u16) -> Result<Child, String> {
+    let venv_python = get_venv_python(app);
     if !venv_python.exists() {
         return Err("Python environment not ready".to_string());
     }
 
-    let python_dir = {
-        let resource_dir = app.path().resource_dir().ok();
-        let bundled_path = resource_dir.as_ref().map(|r| r.join("python-backend"));
-        if bundled_path.as_ref().map(|p| p.exists()).unwrap_or(false) {
-            bundled_path.unwrap()
-        } else {
-            let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-            manifest_dir
-                .parent()
-                .unwrap()
-                .parent()
-                .unwrap()
-                .join("resources")
-                .join("python-backend")
-        }
-    };
-
-    let keero_db_path = get_keero_dir(&app).join("keero.db");
-    let keero_voices_dir = get_voices_dir(&app);
-    let keero_images_dir = get_images_dir(&app);
-    let keero_docs_dir = get_docs_dir(&app);
-
-    ensure_port_free(8000);
+    let python_dir = resolve_python_dir(app);
+    let keero_db_path = get_keero_dir(app).join("keero.db");
+    let keero_voices_dir = get_voices_dir(app);
+    let keero_images_dir = get_images_dir(app);
+    let keero_docs_dir = get_docs_dir(app);
 
     let mut cmd = Command::new(&venv_python);
     cmd.arg("-m")
@@ -107,7 +239,7 @@ pub async fn start_backend(app: AppHandle) -> Result<String, String> {
         .arg("--host")
         .arg("0.0.0.0")
         .arg("--port")
-        .arg("8000")
+        .arg(port.to_string())
         .current_dir(&python_dir)
         .env("KEERO_DB_PATH", keero_db_path.to_string_lossy().to_string())
         .env("KEERO_VOICES_DIR", keero_voices_dir.to_string_lossy().to_string())
@@ -117,89 +249,148 @@ pub async fn start_backend(app: AppHandle) -> Result<String, String> {
         .env("HF_HUB_DISABLE_XET", "1")
         .env("HF_HUB_ENABLE_HF_TRANSFER", "1")
         .env("PYTHONWARNINGS", "ignore::UserWarning:multiprocessing.resource_tracker");
-    if let Some(tesseract) = get_tesseract_cmd(&app) {
+    if let Some(tesseract) = get_tesseract_cmd(app) {
         cmd.env("TESSERACT_CMD", tesseract.to_string_lossy().to_string());
     }
-    let child = cmd
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .map_err(|e| format!("Failed to start backend: {e}"))?;
+    sanitize_sandbox_env(&mut cmd);
 
-    println!("[TAURI] Backend started after setup (PID: {})", child.id());
-    app.manage(ApiProcess(Mutex::new(Some(child))));
+    info!(target: "backend", "Starting backend: {:?} -m uvicorn server:app --host 0.0.0.0 --port {} (cwd: {:?})", venv_python, port, python_dir);
+    let mut child = cmd
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            error!(target: "backend", "Failed to start backend: {}", e);
+            format!("Failed to start backend: {e}")
+        })?;
 
-    Ok("Backend started".to_string())
+    info!(target: "backend", "Backend started (PID: {})", child.id());
+    spawn_log_readers(app, child.stdout.take(), child.stderr.take());
+    Ok(child)
 }
 
-pub fn setup_backend(app: &mut tauri::App) -> Result<(), Box<dyn std::error::Error>> {
-    ensure_port_free(8000);
+/// Watches the running backend: reaps the tracked child via `try_wait` and
+/// restarts it with capped exponential backoff once it has actually exited,
+/// up to a max-attempts ceiling. There's no `/health` route on the bundled
+/// backend in this checkout, so liveness is judged purely by whether the
+/// process is still running — a real health probe can be wired in here once
+/// that endpoint exists. Exits cleanly (no restart) once `SupervisorControl`
+/// is signaled by a deliberate `stop_api_server` call.
+fn start_supervisor(app: AppHandle, port: u16) {
+    if let Some(control) = app.try_state::<SupervisorControl>() {
+        control.0.store(false, Ordering::SeqCst);
+    }
 
-    let app_handle = app.handle();
-    let venv_python = get_venv_python(&app_handle);
+    std::thread::spawn(move || {
+        let mut attempt: u32 = 0;
+        loop {
+            let stop_requested = app
+                .try_state::<SupervisorControl>()
+                .map(|c| c.0.load(Ordering::SeqCst))
+                .unwrap_or(false);
+            if stop_requested {
+                break;
+            }
+
+            std::thread::sleep(CHECK_INTERVAL);
 
-    let python_dir = {
-        let resource_dir = app.path().resource_dir().ok();
-        let bundled_backend = resource_dir.as_ref().map(|r| r.join("python-backend"));
+            let stop_requested = app
+                .try_state::<SupervisorControl>()
+                .map(|c| c.0.load(Ordering::SeqCst))
+                .unwrap_or(false);
+            if stop_requested {
+                break;
+            }
 
-        if bundled_backend.as_ref().map(|p| p.exists()).unwrap_or(false) {
-            bundled_backend.unwrap()
-        } else {
-            let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-            let repo_root = manifest_dir.parent().unwrap().parent().unwrap();
-            repo_root.join("resources").join("python-backend")
+            let exited = match app.try_state::<ApiProcess>() {
+                Some(state) => match state.0.lock() {
+                    Ok(mut guard) => match guard.as_mut() {
+                        Some(child) => matches!(child.try_wait(), Ok(Some(_))),
+                        None => true,
+                    },
+                    Err(_) => false,
+                },
+                None => true,
+            };
+
+            if !exited {
+                attempt = 0;
+                let _ = app.emit("backend-status", "healthy");
+                continue;
+            }
+
+            attempt += 1;
+            warn!(target: "backend", "Backend process exited (attempt {}/{})", attempt, MAX_RESTART_ATTEMPTS);
+            let _ = app.emit("backend-status", "crashed");
+
+            if attempt > MAX_RESTART_ATTEMPTS {
+                error!(target: "backend", "Backend exceeded max restart attempts; giving up");
+                let _ = app.emit("backend-status", "giving-up");
+                break;
+            }
+
+            let backoff_ms = BASE_BACKOFF_MS.saturating_mul(1u64 << (attempt - 1)).min(MAX_BACKOFF_MS);
+            std::thread::sleep(Duration::from_millis(backoff_ms));
+
+            let stop_requested = app
+                .try_state::<SupervisorControl>()
+                .map(|c| c.0.load(Ordering::SeqCst))
+                .unwrap_or(false);
+            if stop_requested {
+                break;
+            }
+
+            let _ = app.emit("backend-status", "starting");
+            ensure_port_free(port);
+            match spawn_backend(&app, port) {
+                Ok(child) => install_api_process(&app, child),
+                Err(e) => {
+                    error!(target: "backend", "Restart attempt {} failed: {}", attempt, e);
+                }
+            }
         }
-    };
+    });
+}
 
-    if !venv_python.exists() {
-        println!("[TAURI] Python env not ready yet (expected: {}). Skipping API server start.", venv_python.display());
-        return Ok(());
+#[tauri::command]
+pub async fn start_backend(app: AppHandle) -> Result<String, String> {
+    let port = backend_port();
+    if TcpStream::connect_timeout(&format!("127.0.0.1:{port}").parse().unwrap(), Duration::from_millis(100))
+        .is_ok()
+    {
+        return Ok("Backend already running".to_string());
     }
 
-    let python_path = venv_python;
+    ensure_port_free(port);
+    let child = spawn_backend(&app, port)?;
+    install_api_process(&app, child);
+    app.manage(SupervisorControl::default());
+    start_supervisor(app.clone(), port);
+    let _ = app.emit("backend-status", "starting");
 
-    println!("[TAURI] Starting Python API server...");
-    println!("[TAURI] Python: {:?}", python_path);
-    println!("[TAURI] Server dir: {:?}", python_dir);
+    Ok("Backend started".to_string())
+}
 
-    let keero_db_path = get_keero_dir(&app_handle).join("keero.db");
-    let keero_voices_dir = get_voices_dir(&app_handle);
-    let keero_images_dir = get_images_dir(&app_handle);
-    let keero_docs_dir = get_docs_dir(&app_handle);
-    println!("[TAURI] DB Path: {:?}", keero_db_path);
+pub fn setup_backend(app: &mut tauri::App) -> Result<(), Box<dyn std::error::Error>> {
+    let port = backend_port();
+    ensure_port_free(port);
 
-    let mut cmd = Command::new(&python_path);
-    cmd.arg("-m")
-        .arg("uvicorn")
-        .arg("server:app")
-        .arg("--host")
-        .arg("0.0.0.0")
-        .arg("--port")
-        .arg("8000")
-        .current_dir(&python_dir)
-        .env("KEERO_DB_PATH", keero_db_path.to_string_lossy().to_string())
-        .env("KEERO_VOICES_DIR", keero_voices_dir.to_string_lossy().to_string())
-        .env("KEERO_IMAGES_DIR", keero_images_dir.to_string_lossy().to_string())
-        .env("KEERO_DOCS_DIR", keero_docs_dir.to_string_lossy().to_string())
-        .env("TOKENIZERS_PARALLELISM", "false")
-        .env("HF_HUB_DISABLE_XET", "1")
-        .env("HF_HUB_ENABLE_HF_TRANSFER", "1")
-        .env("PYTHONWARNINGS", "ignore::UserWarning:multiprocessing.resource_tracker");
-    if let Some(tesseract) = get_tesseract_cmd(&app_handle) {
-        cmd.env("TESSERACT_CMD", tesseract.to_string_lossy().to_string());
+    let app_handle = app.handle();
+    let venv_python = get_venv_python(&app_handle);
+    if !venv_python.exists() {
+        warn!(target: "backend", "Python env not ready yet (expected: {}). Skipping API server start.", venv_python.display());
+        return Ok(());
     }
-    let child = cmd
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn();
 
-    match child {
+    match spawn_backend(&app_handle, port) {
         Ok(child) => {
-            println!("[TAURI] Python API server started (PID: {})", child.id());
-            app.manage(ApiProcess(Mutex::new(Some(child))));
+            install_api_process(&app_handle, child);
+            app.manage(SupervisorControl::default());
+            start_supervisor(app_handle.clone(), port);
+            let _ = app_handle.emit("backend-status", "starting");
         }
         Err(e) => {
-            eprintln!("[TAURI] Failed to start Python API server: {}", e);
+            error!(target: "backend", "Failed to start Python API server: {}", e);
         }
     }
 
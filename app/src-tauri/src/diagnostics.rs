@@ -0,0 +1,120 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::models::{self, ModelStatus};
+use crate::paths::{
+    bootstrap_python_if_needed, get_bootstrap_python_root, get_docs_dir, get_images_dir,
+    get_keero_dir, get_tesseract_cmd, get_venv_python, get_voices_dir,
+};
+use crate::python_setup;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathCheck {
+    pub path: String,
+    pub exists: bool,
+}
+
+impl PathCheck {
+    fn of(path: &PathBuf) -> Self {
+        PathCheck {
+            path: path.to_string_lossy().to_string(),
+            exists: path.exists(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagnosticsReport {
+    pub keero_dir: PathCheck,
+    pub voices_dir: PathCheck,
+    pub images_dir: PathCheck,
+    pub docs_dir: PathCheck,
+    pub venv_python: PathCheck,
+    pub bootstrap_python_root: PathCheck,
+    pub tesseract_cmd: Option<PathCheck>,
+    pub bundled_python_version: Option<String>,
+    pub venv_python_version: Option<String>,
+    pub pyproject_dependencies: Vec<String>,
+    pub model_status: Option<ModelStatus>,
+    pub free_disk_space_bytes: Option<u64>,
+    pub cpu_arch: String,
+    pub os: String,
+    pub apple_silicon_guard_passed: bool,
+}
+
+/// Resolves an interpreter's `--version` output, shared with the doctor
+/// report so both diagnostics views agree on what "Python version" means.
+pub(crate) fn python_version(python: &PathBuf) -> Option<String> {
+    if !python.exists() {
+        return None;
+    }
+    let output = Command::new(python).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    // Python 2 prints its version to stderr, Python 3 to stdout.
+    let text = if !output.stdout.is_empty() {
+        String::from_utf8_lossy(&output.stdout).to_string()
+    } else {
+        String::from_utf8_lossy(&output.stderr).to_string()
+    };
+    Some(text.trim().to_string())
+}
+
+fn free_disk_space_bytes(path: &PathBuf) -> Option<u64> {
+    #[cfg(unix)]
+    {
+        let output = Command::new("df").arg("-Pk").arg(path).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        let text = String::from_utf8_lossy(&output.stdout);
+        let line = text.lines().nth(1)?;
+        let available_kb: u64 = line.split_whitespace().nth(3)?.parse().ok()?;
+        Some(available_kb * 1024)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = path;
+        None
+    }
+}
+
+/// Collects a single serializable snapshot of the runtime environment so users
+/// can paste one report instead of guessing why setup failed.
+#[tauri::command]
+pub async fn diagnostics(app: AppHandle) -> Result<DiagnosticsReport, String> {
+    let keero_dir = get_keero_dir(&app);
+    let venv_python = get_venv_python(&app);
+    let bootstrap_python_root = get_bootstrap_python_root(&app);
+
+    let bundled_python = bootstrap_python_if_needed(&app)
+        .ok()
+        .unwrap_or_else(|| bootstrap_python_root.join("python").join("bin").join("python"));
+
+    let tesseract_cmd = get_tesseract_cmd(&app).map(|p| PathCheck::of(&p));
+
+    let pyproject_dependencies = python_setup::pyproject_dependency_names(&app).unwrap_or_default();
+    let model_status = models::check_models_status(app.clone()).await.ok();
+
+    Ok(DiagnosticsReport {
+        keero_dir: PathCheck::of(&keero_dir),
+        voices_dir: PathCheck::of(&get_voices_dir(&app)),
+        images_dir: PathCheck::of(&get_images_dir(&app)),
+        docs_dir: PathCheck::of(&get_docs_dir(&app)),
+        venv_python: PathCheck::of(&venv_python),
+        bootstrap_python_root: PathCheck::of(&bootstrap_python_root),
+        tesseract_cmd,
+        bundled_python_version: python_version(&bundled_python),
+        venv_python_version: python_version(&venv_python),
+        pyproject_dependencies,
+        model_status,
+        free_disk_space_bytes: free_disk_space_bytes(&keero_dir),
+        cpu_arch: std::env::consts::ARCH.to_string(),
+        os: std::env::consts::OS.to_string(),
+        apple_silicon_guard_passed: cfg!(target_arch = "aarch64"),
+    })
+}
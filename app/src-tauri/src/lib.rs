@@ -1,4 +1,6 @@
 mod backend;
+mod diagnostics;
+mod logging;
 mod models;
 mod paths;
 mod python_setup;
@@ -11,7 +13,16 @@ use tauri::Manager;
 pub fn run() {
     let app = tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        .manage(models::DownloadManager::default())
+        .manage(backend::BackendLogBuffer::default())
         .setup(|app| {
+            let log_path = logging::init_logging(&app.handle())
+                .unwrap_or_else(|e| {
+                    eprintln!("[TAURI] Failed to initialize logging: {}", e);
+                    std::path::PathBuf::new()
+                });
+            app.manage(logging::LogPath(log_path));
+
             backend::setup_backend(app)?;
             let min_size = Some(tauri::LogicalSize::<f64> { width: 800.0, height: 600.0 });
             if let Some(w) = app.get_webview_window("main") {
@@ -23,16 +34,24 @@ pub fn run() {
             setup::check_setup_status,
             setup::create_python_venv,
             setup::install_python_deps,
+            python_setup::verify_python_deps,
+            python_setup::doctor_report,
             models::check_models_status,
             models::scan_local_models,
             models::download_model,
             models::download_all_models,
+            models::cancel_download,
+            models::verify_model,
             setup::mark_setup_complete,
             setup::is_first_launch,
             setup::tesseract_status,
             setup::tesseract_install,
             backend::start_backend,
-            voices::save_voice_wav_base64
+            backend::get_backend_logs,
+            voices::save_voice_wav_base64,
+            diagnostics::diagnostics,
+            logging::get_log_path,
+            logging::open_logs
         ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application");
@@ -0,0 +1,106 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use tauri::AppHandle;
+
+use crate::paths::get_keero_dir;
+
+const LOG_FILE_NAME: &str = "pixm8.log";
+const MAX_LOG_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Path to the active log file, stashed in Tauri state so `get_log_path` and
+/// `open_logs` don't need to recompute it.
+pub struct LogPath(pub PathBuf);
+
+struct FileAndStderrLogger {
+    file: Mutex<File>,
+}
+
+fn timestamp() -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    format!("{}.{:03}", now.as_secs(), now.subsec_millis())
+}
+
+impl Log for FileAndStderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Debug
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let line = format!(
+            "[{}] {:>5} {}: {}\n",
+            timestamp(),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+        eprint!("{}", line);
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.write_all(line.as_bytes());
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.flush();
+        }
+    }
+}
+
+/// Initializes the process-wide logger: writes leveled, qualified log lines
+/// to both stderr and a rotating file under `<keero_dir>/logs`, so a broken
+/// setup leaves a reproducible trail instead of a swallowed `.ok()`.
+pub fn init_logging(app: &AppHandle) -> Result<PathBuf, String> {
+    let logs_dir = get_keero_dir(app).join("logs");
+    fs::create_dir_all(&logs_dir).map_err(|e| format!("Failed to create logs directory: {}", e))?;
+
+    let log_path = logs_dir.join(LOG_FILE_NAME);
+
+    if let Ok(metadata) = fs::metadata(&log_path) {
+        if metadata.len() > MAX_LOG_BYTES {
+            let rotated = logs_dir.join(format!("{}.1", LOG_FILE_NAME));
+            let _ = fs::remove_file(&rotated);
+            let _ = fs::rename(&log_path, &rotated);
+        }
+    }
+
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log_path)
+        .map_err(|e| format!("Failed to open log file {}: {}", log_path.display(), e))?;
+
+    let logger = Box::new(FileAndStderrLogger {
+        file: Mutex::new(file),
+    });
+    log::set_boxed_logger(logger)
+        .map(|_| log::set_max_level(LevelFilter::Debug))
+        .map_err(|e| format!("Failed to initialize logger: {}", e))?;
+
+    Ok(log_path)
+}
+
+#[tauri::command]
+pub async fn get_log_path(app: AppHandle) -> Result<String, String> {
+    use tauri::Manager;
+    Ok(app.state::<LogPath>().0.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+pub async fn open_logs(app: AppHandle) -> Result<(), String> {
+    use tauri::Manager;
+    use tauri_plugin_opener::OpenerExt;
+
+    let path = app.state::<LogPath>().0.clone();
+    app.opener()
+        .open_path(path.to_string_lossy().to_string(), None::<&str>)
+        .map_err(|e| format!("Failed to open logs: {}", e))
+}
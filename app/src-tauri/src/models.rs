@@ -1,13 +1,23 @@
+use std::collections::HashMap;
 use std::env;
 use std::ffi::OsStr;
 use std::fs;
+use std::io::Read;
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+use std::time::Duration;
 
+use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
-use tauri::{AppHandle, Emitter};
+use tauri::{AppHandle, Emitter, Manager};
 
-use crate::paths::get_venv_python;
+use crate::paths::{get_venv_python, normalize_python_command_env};
+
+/// Tracks the `snapshot_download` child processes spawned by `download_model`,
+/// keyed by repo_id, so an in-flight download can be cancelled from the UI.
+#[derive(Default)]
+pub struct DownloadManager(pub Mutex<HashMap<String, Child>>);
 
 const STT: &str = "mlx-community/whisper-large-v3-turbo";
 const LLM: &str = "mlx-community/Ministral-3-3B-Instruct-2512-4bit";
@@ -29,6 +39,136 @@ pub struct ModelStatus {
     pub all_downloaded: bool,
 }
 
+/// A single progress update for an in-flight `snapshot_download`.
+///
+/// `model_id` is set when the download was kicked off by `download_all_models`
+/// (e.g. "stt"/"llm"/"tts") so the UI can tell which of the set is active.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelDownloadProgress {
+    pub repo_id: String,
+    pub model_id: Option<String>,
+    pub file: Option<String>,
+    pub bytes_downloaded: u64,
+    pub bytes_total: u64,
+    pub percentage: f32,
+    pub eta_seconds: Option<u64>,
+    pub file_complete: bool,
+    pub model_complete: bool,
+}
+
+impl ModelDownloadProgress {
+    fn starting(repo_id: &str, model_id: Option<&str>) -> Self {
+        ModelDownloadProgress {
+            repo_id: repo_id.to_string(),
+            model_id: model_id.map(str::to_string),
+            file: None,
+            bytes_downloaded: 0,
+            bytes_total: 0,
+            percentage: 0.0,
+            eta_seconds: None,
+            file_complete: false,
+            model_complete: false,
+        }
+    }
+
+    fn finished(repo_id: &str, model_id: Option<&str>) -> Self {
+        ModelDownloadProgress {
+            repo_id: repo_id.to_string(),
+            model_id: model_id.map(str::to_string),
+            file: None,
+            bytes_downloaded: 0,
+            bytes_total: 0,
+            percentage: 100.0,
+            eta_seconds: Some(0),
+            file_complete: true,
+            model_complete: true,
+        }
+    }
+}
+
+/// Parses a `u64` byte count out of a tqdm-style size token like "450M" or "1.00G".
+fn parse_size_token(token: &str) -> Option<u64> {
+    let token = token.trim();
+    let (number_part, multiplier) = if let Some(n) = token.strip_suffix('G') {
+        (n, 1024u64 * 1024 * 1024)
+    } else if let Some(n) = token.strip_suffix('M') {
+        (n, 1024u64 * 1024)
+    } else if let Some(n) = token.strip_suffix('K') {
+        (n, 1024u64)
+    } else {
+        (token, 1)
+    };
+    let value: f64 = number_part.trim().parse().ok()?;
+    Some((value * multiplier as f64) as u64)
+}
+
+/// Parses a "mm:ss" or "hh:mm:ss" tqdm duration token into seconds.
+fn parse_duration_token(token: &str) -> Option<u64> {
+    let mut seconds: u64 = 0;
+    for part in token.trim().split(':') {
+        let part: u64 = part.parse().ok()?;
+        seconds = seconds * 60 + part;
+    }
+    Some(seconds)
+}
+
+/// Parses a single line of `huggingface_hub`/tqdm progress output, e.g.:
+/// `model.safetensors:  45%|####5     | 450M/1.00G [00:12<00:15, 35.2MB/s]`
+fn parse_progress_line(line: &str) -> Option<(String, u64, u64, f32, Option<u64>)> {
+    let (filename, rest) = line.split_once(':')?;
+    let filename = filename.trim();
+    if filename.is_empty() || filename.contains(' ') {
+        return None;
+    }
+
+    let percent_idx = rest.find('%')?;
+    let percentage: f32 = rest[..percent_idx].trim().parse().ok()?;
+
+    let first_bar = rest.find('|')?;
+    let second_bar = rest[first_bar + 1..].find('|')? + first_bar + 1;
+    let after_bar = rest[second_bar + 1..].trim_start();
+    let sizes_end = after_bar.find(' ').unwrap_or(after_bar.len());
+    let (current_str, total_str) = after_bar[..sizes_end].split_once('/')?;
+
+    let current = parse_size_token(current_str)?;
+    let total = parse_size_token(total_str)?;
+
+    let eta_seconds = rest
+        .find('<')
+        .and_then(|lt| rest[lt + 1..].find(',').map(|comma| &rest[lt + 1..lt + 1 + comma]))
+        .and_then(parse_duration_token);
+
+    Some((filename.to_string(), current, total, percentage, eta_seconds))
+}
+
+/// Reads `reader` byte-by-byte, yielding one `String` per frame delimited by
+/// `\n` or `\r`. tqdm/`hf_transfer` progress bars redraw the same line with
+/// `\r` and only emit a trailing `\n` once a file finishes, so splitting on
+/// `\n` alone (e.g. `BufRead::lines`) misses every mid-file update.
+fn read_progress_frames<R: Read>(mut reader: R, mut on_frame: impl FnMut(&str)) {
+    let mut buf = Vec::new();
+    let mut byte = [0u8; 1];
+    loop {
+        match reader.read(&mut byte) {
+            Ok(0) => break,
+            Ok(_) => {
+                if matches!(byte[0], b'\n' | b'\r') {
+                    if !buf.is_empty() {
+                        on_frame(&String::from_utf8_lossy(&buf));
+                        buf.clear();
+                    }
+                } else {
+                    buf.push(byte[0]);
+                }
+            }
+            Err(_) => break,
+        }
+    }
+    if !buf.is_empty() {
+        on_frame(&String::from_utf8_lossy(&buf));
+    }
+}
+
 fn get_dir_size(path: &PathBuf) -> u64 {
     let mut total_size = 0;
     if let Ok(entries) = fs::read_dir(path) {
@@ -126,9 +266,11 @@ pub async fn check_models_status(_app: AppHandle) -> Result<ModelStatus, String>
 
     for model in &mut models {
         if let Some(path) = get_model_path(&hf_cache, &model.repo_id) {
-            model.downloaded = true;
-            let size = get_dir_size(&path);
-            model.size_estimate = Some(format_size(size));
+            if snapshot_is_complete(&path) {
+                model.downloaded = true;
+                let size = get_dir_size(&path);
+                model.size_estimate = Some(format_size(size));
+            }
         }
     }
 
@@ -194,49 +336,253 @@ pub async fn scan_local_models(_app: AppHandle) -> Result<Vec<ModelInfo>, String
     Ok(models)
 }
 
-#[tauri::command]
-pub async fn download_model(app: AppHandle, repo_id: String) -> Result<String, String> {
-    let venv_python = get_venv_python(&app);
+fn download_model_internal(app: &AppHandle, repo_id: &str, model_id: Option<&str>) -> Result<String, String> {
+    let venv_python = get_venv_python(app);
 
     if !venv_python.exists() {
         return Err("Python environment not set up. Please complete setup first.".to_string());
     }
 
-    app.emit("model-download-progress", format!("Downloading {}...", repo_id))
-        .ok();
+    app.emit(
+        "model-download-progress",
+        ModelDownloadProgress::starting(repo_id, model_id),
+    )
+    .ok();
 
     let script = format!(
         r#"from huggingface_hub import snapshot_download; snapshot_download(repo_id="{}")"#,
         repo_id
     );
 
-    let output = Command::new(venv_python.to_str().unwrap())
-        .arg("-c")
-        .arg(&script)
-        .output()
-        .map_err(|e| format!("Failed to download model: {}", e))?;
+    let mut cmd = Command::new(venv_python.to_str().unwrap());
+    cmd.arg("-c").arg(&script).env("HF_HUB_ENABLE_HF_TRANSFER", "1");
+    normalize_python_command_env(&mut cmd, app);
+    info!(target: "models", "Starting download of {} via {:?} -c \"{}\"", repo_id, venv_python, script);
+    let mut child = cmd
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            error!(target: "models", "Failed to spawn download for {}: {}", repo_id, e);
+            format!("Failed to start download: {}", e)
+        })?;
+
+    // snapshot_download renders its tqdm progress bars to stderr.
+    let stderr = child.stderr.take();
+    let stderr_tail = std::sync::Arc::new(Mutex::new(Vec::<String>::new()));
+    let reader_handle = stderr.map(|stderr| {
+        let app = app.clone();
+        let repo_id = repo_id.to_string();
+        let model_id = model_id.map(str::to_string);
+        let stderr_tail = stderr_tail.clone();
+        std::thread::spawn(move || {
+            read_progress_frames(stderr, |line| {
+                if let Some((file, current, total, percentage, eta_seconds)) = parse_progress_line(line) {
+                    app.emit(
+                        "model-download-progress",
+                        ModelDownloadProgress {
+                            repo_id: repo_id.clone(),
+                            model_id: model_id.clone(),
+                            file: Some(file),
+                            bytes_downloaded: current,
+                            bytes_total: total,
+                            percentage,
+                            eta_seconds,
+                            file_complete: percentage >= 100.0,
+                            model_complete: false,
+                        },
+                    )
+                    .ok();
+                } else if let Ok(mut tail) = stderr_tail.lock() {
+                    tail.push(line.to_string());
+                    if tail.len() > 20 {
+                        tail.remove(0);
+                    }
+                }
+            });
+        })
+    });
+
+    // Track the child in shared state so `cancel_download` can kill it, and poll
+    // for completion instead of blocking on `wait()` so a cancellation (which
+    // removes the entry) can be observed without holding the child hostage.
+    {
+        let manager = app.state::<DownloadManager>();
+        manager
+            .0
+            .lock()
+            .map_err(|_| "Download manager lock poisoned".to_string())?
+            .insert(repo_id.to_string(), child);
+    }
 
-    if !output.status.success() {
+    let status = loop {
+        std::thread::sleep(Duration::from_millis(200));
+        let manager = app.state::<DownloadManager>();
+        let mut guard = manager
+            .0
+            .lock()
+            .map_err(|_| "Download manager lock poisoned".to_string())?;
+        let Some(tracked) = guard.get_mut(repo_id) else {
+            return Err(format!("Download of {} was cancelled", repo_id));
+        };
+        if let Some(status) = tracked
+            .try_wait()
+            .map_err(|e| format!("Failed to poll download: {}", e))?
+        {
+            guard.remove(repo_id);
+            break status;
+        }
+    };
+
+    if let Some(handle) = reader_handle {
+        let _ = handle.join();
+    }
+
+    if !status.success() {
+        let tail = stderr_tail.lock().map(|t| t.join("\n")).unwrap_or_default();
+        error!(
+            target: "models",
+            "Download of {} exited with {}: {}",
+            repo_id, status, tail
+        );
         return Err(format!(
-            "Failed to download model: {}",
-            String::from_utf8_lossy(&output.stderr)
+            "Failed to download model {}: {}",
+            repo_id,
+            if tail.is_empty() { status.to_string() } else { tail }
         ));
     }
 
+    info!(target: "models", "Download of {} completed successfully", repo_id);
     app.emit(
         "model-download-progress",
-        format!("Downloaded {} successfully!", repo_id),
+        ModelDownloadProgress::finished(repo_id, model_id),
     )
     .ok();
     Ok(format!("Model {} downloaded successfully", repo_id))
 }
 
+/// Kills an in-flight download started by `download_model`/`download_all_models`.
+#[tauri::command]
+pub async fn cancel_download(app: AppHandle, repo_id: String) -> Result<(), String> {
+    let manager = app.state::<DownloadManager>();
+    let mut guard = manager
+        .0
+        .lock()
+        .map_err(|_| "Download manager lock poisoned".to_string())?;
+    let mut child = guard
+        .remove(&repo_id)
+        .ok_or_else(|| format!("No active download for {}", repo_id))?;
+
+    #[cfg(unix)]
+    {
+        let _ = Command::new("pkill").args(["-P", &child.id().to_string()]).output();
+    }
+    child.kill().map_err(|e| {
+        error!(target: "models", "Failed to cancel download of {}: {}", repo_id, e);
+        format!("Failed to cancel download: {}", e)
+    })?;
+    warn!(target: "models", "Cancelled download of {}", repo_id);
+    Ok(())
+}
+
+/// Extracts the shard filenames (`"model-00001-of-00002.safetensors"`, ...)
+/// listed in a `model.safetensors.index.json`'s `weight_map`.
+fn index_json_shard_filenames(index_json: &str) -> Vec<String> {
+    let mut files: Vec<String> = index_json
+        .split('"')
+        .skip(1)
+        .step_by(2)
+        .filter(|part| part.ends_with(".safetensors"))
+        .map(|part| part.to_string())
+        .collect();
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// Confirms every file already present in a snapshot resolves to a real blob
+/// (a broken symlink means the corresponding blob was never fully written).
+fn entries_resolve(dir: &PathBuf) -> bool {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return false;
+    };
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        if path.is_dir() {
+            if !entries_resolve(&path) {
+                return false;
+            }
+        } else if fs::metadata(&path).is_err() {
+            // `metadata` follows symlinks; an error here means the blob the
+            // snapshot file points at doesn't exist.
+            return false;
+        }
+    }
+    true
+}
+
+/// Confirms a downloaded snapshot is actually complete, not just internally
+/// consistent. `entries_resolve` alone only catches files that started
+/// downloading and got a broken symlink; a file whose download was never
+/// even attempted (process killed before `snapshot_download` reached it)
+/// leaves nothing broken and would otherwise read as "downloaded". So this
+/// also checks for blob downloads still in progress, and cross-checks
+/// sharded checkpoints against their own manifest of expected files.
+fn snapshot_is_complete(snapshot_dir: &PathBuf) -> bool {
+    if !entries_resolve(snapshot_dir) {
+        return false;
+    }
+
+    // `snapshot_download` writes each blob to `<repo_cache>/blobs/<hash>.incomplete`
+    // and only renames it once the download finishes, so a leftover marker in
+    // the cache entry's blobs dir (a sibling of `snapshots/`) means a file
+    // was still in flight when the process stopped.
+    if let Some(blobs_dir) = snapshot_dir.parent().and_then(|p| p.parent()).map(|p| p.join("blobs")) {
+        if let Ok(entries) = fs::read_dir(&blobs_dir) {
+            for entry in entries.filter_map(Result::ok) {
+                if entry.path().extension().is_some_and(|ext| ext == "incomplete") {
+                    return false;
+                }
+            }
+        }
+    }
+
+    if let Ok(index_json) = fs::read_to_string(snapshot_dir.join("model.safetensors.index.json")) {
+        for shard in index_json_shard_filenames(&index_json) {
+            if fs::metadata(snapshot_dir.join(&shard)).is_err() {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+#[tauri::command]
+pub async fn verify_model(_app: AppHandle, repo_id: String) -> Result<bool, String> {
+    let home = env::var("HOME").unwrap_or_else(|_| ".".to_string());
+    let hf_cache = PathBuf::from(&home)
+        .join(".cache")
+        .join("huggingface")
+        .join("hub");
+
+    match get_model_path(&hf_cache, &repo_id) {
+        Some(snapshot_dir) => Ok(snapshot_is_complete(&snapshot_dir)),
+        None => Ok(false),
+    }
+}
+
+#[tauri::command]
+pub async fn download_model(app: AppHandle, repo_id: String) -> Result<String, String> {
+    download_model_internal(&app, &repo_id, None)
+}
+
 #[tauri::command]
 pub async fn download_all_models(app: AppHandle) -> Result<String, String> {
-    let models = vec![STT, LLM, TTS];
+    let models = [("stt", STT), ("llm", LLM), ("tts", TTS)];
 
-    for repo_id in models {
-        download_model(app.clone(), repo_id.to_string()).await?;
+    for (model_id, repo_id) in models {
+        download_model_internal(&app, repo_id, Some(model_id))?;
     }
 
     Ok("All models downloaded successfully".to_string())
@@ -1,4 +1,7 @@
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 
 use tauri::{AppHandle, Manager};
 
@@ -121,6 +124,169 @@ pub(crate) fn get_venv_pip(app: &AppHandle) -> PathBuf {
     }
 }
 
+/// Detects whether the app is running inside a known Linux application
+/// sandbox format, whose launcher commonly injects loader/plugin paths that
+/// point inside the bundle rather than the host system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SandboxKind {
+    AppImage,
+    Flatpak,
+    Snap,
+}
+
+pub(crate) fn detect_sandbox() -> Option<SandboxKind> {
+    if std::env::var_os("APPIMAGE").is_some() || std::env::var_os("APPDIR").is_some() {
+        Some(SandboxKind::AppImage)
+    } else if std::path::Path::new("/.flatpak-info").exists()
+        || std::env::var("container").ok().as_deref() == Some("flatpak")
+    {
+        Some(SandboxKind::Flatpak)
+    } else if std::env::var_os("SNAP").is_some() {
+        Some(SandboxKind::Snap)
+    } else {
+        None
+    }
+}
+
+/// Applies a clean, predictable environment to a `Command` that spawns the
+/// bundled/venv Python or pip: dedups `PATH` (keeping first-seen order) with
+/// the venv's own bin/Scripts dir prepended, and strips loader/runtime
+/// variables a packaged `.app`/AppImage/Flatpak/Snap commonly injects
+/// (`DYLD_LIBRARY_PATH`, `LD_LIBRARY_PATH`, `PYTHONHOME`) which can make the
+/// bundled interpreter load the wrong shared libraries.
+pub(crate) fn normalize_python_command_env(cmd: &mut Command, app: &AppHandle) {
+    let venv_bin = if cfg!(target_os = "windows") {
+        get_venv_path(app).join("Scripts")
+    } else {
+        get_venv_path(app).join("bin")
+    };
+
+    let mut seen = HashSet::new();
+    let mut entries: Vec<OsString> = vec![venv_bin.clone().into_os_string()];
+    seen.insert(venv_bin.into_os_string());
+
+    if let Some(path) = std::env::var_os("PATH") {
+        for entry in std::env::split_paths(&path) {
+            if entry.as_os_str().is_empty() {
+                continue;
+            }
+            let entry = entry.into_os_string();
+            if seen.insert(entry.clone()) {
+                entries.push(entry);
+            }
+        }
+    }
+
+    if let Ok(joined) = std::env::join_paths(entries) {
+        cmd.env("PATH", joined);
+    }
+
+    for var in ["DYLD_LIBRARY_PATH", "LD_LIBRARY_PATH", "PYTHONHOME"] {
+        cmd.env_remove(var);
+    }
+
+    // AppImage/Flatpak/Snap launchers commonly point PYTHONPATH inside the
+    // bundle; clear it so the venv's own site-packages layout wins.
+    if detect_sandbox().is_some() {
+        cmd.env_remove("PYTHONPATH");
+    }
+}
+
+/// Best-effort root directory of the detected sandbox bundle, used to tell
+/// whether a PATH-style entry points back inside the bundle rather than the
+/// host system.
+fn sandbox_bundle_root(kind: SandboxKind) -> Option<PathBuf> {
+    match kind {
+        SandboxKind::AppImage => std::env::var_os("APPDIR").map(PathBuf::from),
+        SandboxKind::Flatpak => Some(PathBuf::from("/app")),
+        SandboxKind::Snap => std::env::var_os("SNAP").map(PathBuf::from),
+    }
+}
+
+fn resolves_inside(entry: &Path, bundle_root: &Path) -> bool {
+    let canon_entry = entry.canonicalize().unwrap_or_else(|_| entry.to_path_buf());
+    let canon_root = bundle_root
+        .canonicalize()
+        .unwrap_or_else(|_| bundle_root.to_path_buf());
+    canon_entry.starts_with(&canon_root)
+}
+
+/// Removes duplicate entries, keeping each value at its last (lowest-priority)
+/// occurrence rather than its first, so a sandbox-injected duplicate that was
+/// re-appended further down the variable wins the single remaining slot.
+fn dedupe_keep_last(entries: Vec<OsString>) -> Vec<OsString> {
+    let mut last_index: HashMap<&OsString, usize> = HashMap::new();
+    for (i, entry) in entries.iter().enumerate() {
+        last_index.insert(entry, i);
+    }
+    entries
+        .iter()
+        .enumerate()
+        .filter(|(i, entry)| last_index.get(entry) == Some(i))
+        .map(|(_, entry)| entry.clone())
+        .collect()
+}
+
+/// Cleans a single PATH-style variable's value: drops entries resolving
+/// inside the sandbox bundle root, then dedupes. Returns `None` if nothing is
+/// left, so the caller unsets the variable instead of exporting an empty string.
+fn sanitize_path_var(raw: &OsString, bundle_root: Option<&PathBuf>) -> Option<OsString> {
+    let entries: Vec<OsString> = std::env::split_paths(raw)
+        .filter(|entry| !entry.as_os_str().is_empty())
+        .map(|entry| entry.into_os_string())
+        .collect();
+
+    let filtered: Vec<OsString> = match bundle_root {
+        Some(root) => entries
+            .into_iter()
+            .filter(|entry| !resolves_inside(Path::new(entry), root))
+            .collect(),
+        None => entries,
+    };
+
+    let deduped = dedupe_keep_last(filtered);
+    if deduped.is_empty() {
+        return None;
+    }
+    std::env::join_paths(deduped).ok()
+}
+
+const SANDBOX_PATH_VARS: [&str; 4] = ["PATH", "LD_LIBRARY_PATH", "GST_PLUGIN_PATH", "XDG_DATA_DIRS"];
+
+/// Applied to `cmd` right before spawning the backend: on a detected Linux
+/// sandbox (AppImage/Flatpak/Snap), strips launcher-injected entries that
+/// point back inside the bundle from PATH-style variables, unsetting ones
+/// left empty, and clears `PYTHONHOME`/`PYTHONPATH` outright so the venv's
+/// own interpreter layout is used. No-op on Windows/macOS and outside a
+/// detected sandbox.
+pub(crate) fn sanitize_sandbox_env(cmd: &mut Command) {
+    if !cfg!(target_os = "linux") {
+        return;
+    }
+
+    let Some(kind) = detect_sandbox() else {
+        return;
+    };
+    let bundle_root = sandbox_bundle_root(kind);
+
+    for var in SANDBOX_PATH_VARS {
+        let Some(raw) = std::env::var_os(var) else {
+            continue;
+        };
+        match sanitize_path_var(&raw, bundle_root.as_ref()) {
+            Some(cleaned) => {
+                cmd.env(var, cleaned);
+            }
+            None => {
+                cmd.env_remove(var);
+            }
+        }
+    }
+
+    cmd.env_remove("PYTHONHOME");
+    cmd.env_remove("PYTHONPATH");
+}
+
 /// Directory where Tesseract can be installed (app data, not bundled).
 pub(crate) fn get_tesseract_dir(app: &AppHandle) -> PathBuf {
     get_keero_dir(app).join("tesseract")
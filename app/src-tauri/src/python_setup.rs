@@ -1,8 +1,14 @@
+use std::collections::HashMap;
+use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
 
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Manager};
 
+use crate::paths::{get_keero_dir, get_venv_path, get_venv_python, normalize_python_command_env};
+
 fn parse_pyproject_dependencies(pyproject: &str) -> Vec<String> {
     let mut deps: Vec<String> = Vec::new();
     let mut in_deps = false;
@@ -40,32 +46,274 @@ fn parse_pyproject_dependencies(pyproject: &str) -> Vec<String> {
     deps
 }
 
-fn normalize_dependency_name(spec: &str) -> Option<String> {
+/// Splits a PEP 508 dependency spec into its package name and the raw
+/// version/URL requirement that follows it (environment markers stripped).
+fn parse_dependency_spec(spec: &str) -> Option<(String, String)> {
     let trimmed = spec.split(';').next().unwrap_or("").trim();
     if trimmed.is_empty() {
         return None;
     }
 
-    let before_at = trimmed.split('@').next().unwrap_or("").trim();
-    if before_at.is_empty() {
-        return None;
+    if let Some((name_part, url_part)) = trimmed.split_once('@') {
+        let name = name_part.split('[').next().unwrap_or("").trim();
+        if name.is_empty() {
+            return None;
+        }
+        return Some((name.to_string(), url_part.trim().to_string()));
     }
 
-    let mut end = before_at.len();
-    for (idx, ch) in before_at.char_indices() {
+    let mut end = trimmed.len();
+    for (idx, ch) in trimmed.char_indices() {
         if matches!(ch, '=' | '<' | '>' | '!' | '~') {
             end = idx;
             break;
         }
     }
 
-    let name = &before_at[..end];
-    let name = name.split('[').next().unwrap_or("").trim();
+    let name = trimmed[..end].split('[').next().unwrap_or("").trim();
     if name.is_empty() {
+        return None;
+    }
+    Some((name.to_string(), trimmed[end..].trim().to_string()))
+}
+
+fn normalize_dependency_name(spec: &str) -> Option<String> {
+    parse_dependency_spec(spec).map(|(name, _)| name)
+}
+
+/// PEP 503-style normalization so "Pillow", "pillow" and "typing_extensions" /
+/// "typing-extensions" compare equal to what `pip freeze` reports.
+fn canonical_name(name: &str) -> String {
+    name.to_lowercase().replace('_', "-")
+}
+
+fn parse_dotted_version(v: &str) -> Option<Vec<u32>> {
+    let core = v.split(|c| c == '+' || c == '-').next().unwrap_or(v);
+    core.split('.').map(|p| p.parse::<u32>().ok()).collect()
+}
+
+fn compare_versions(installed: &str, required: &str) -> Option<std::cmp::Ordering> {
+    let mut installed = parse_dotted_version(installed)?;
+    let mut required = parse_dotted_version(required)?;
+    // PEP 440 pads the shorter release with zeros, so "1.0" == "1.0.0".
+    let len = installed.len().max(required.len());
+    installed.resize(len, 0);
+    required.resize(len, 0);
+    Some(installed.cmp(&required))
+}
+
+/// Checks an installed version against a comma-separated set of PEP 440
+/// clauses (e.g. ">=0.100,<1.0"). Clauses that don't parse as plain dotted
+/// versions (git URLs, pre-release suffixes, ...) are treated as satisfied
+/// rather than flagged, since we can't confidently compare them.
+fn satisfies_requirement(installed: &str, requirement: &str) -> bool {
+    let requirement = requirement.trim();
+    if requirement.is_empty() {
+        return true;
+    }
+
+    requirement.split(',').all(|clause| {
+        let clause = clause.trim();
+        if clause.is_empty() {
+            return true;
+        }
+
+        let (op, version) = if let Some(v) = clause.strip_prefix(">=") {
+            (">=", v)
+        } else if let Some(v) = clause.strip_prefix("<=") {
+            ("<=", v)
+        } else if let Some(v) = clause.strip_prefix("==") {
+            ("==", v)
+        } else if let Some(v) = clause.strip_prefix("!=") {
+            ("!=", v)
+        } else if let Some(v) = clause.strip_prefix("~=") {
+            ("~=", v)
+        } else if let Some(v) = clause.strip_prefix('>') {
+            (">", v)
+        } else if let Some(v) = clause.strip_prefix('<') {
+            ("<", v)
+        } else {
+            ("==", clause)
+        };
+
+        match compare_versions(installed, version.trim()) {
+            Some(ord) => match op {
+                ">=" => ord != std::cmp::Ordering::Less,
+                // "~=X.Y.Z" means ">=X.Y.Z" and "==X.Y.*".
+                "~=" => {
+                    ord != std::cmp::Ordering::Less
+                        && match (parse_dotted_version(installed), parse_dotted_version(version.trim())) {
+                            (Some(i), Some(r)) if r.len() > 1 => i.starts_with(&r[..r.len() - 1]),
+                            _ => true,
+                        }
+                }
+                "<=" => ord != std::cmp::Ordering::Greater,
+                "==" => ord == std::cmp::Ordering::Equal,
+                "!=" => ord != std::cmp::Ordering::Equal,
+                ">" => ord == std::cmp::Ordering::Greater,
+                "<" => ord == std::cmp::Ordering::Less,
+                _ => true,
+            },
+            None => true,
+        }
+    })
+}
+
+/// Parses `pip freeze`/`uv pip freeze` output (plain `name==version` lines)
+/// into a canonical-name -> version map.
+fn parse_freeze_output(text: &str) -> HashMap<String, String> {
+    let mut installed = HashMap::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        // Editable/VCS installs (e.g. "-e git+...") don't carry a plain version.
+        if let Some((name, version)) = line.split_once("==") {
+            installed.insert(canonical_name(name), version.trim().to_string());
+        }
+    }
+    installed
+}
+
+fn installed_package_versions_pip(app: &AppHandle, python: &PathBuf) -> Result<HashMap<String, String>, String> {
+    let mut cmd = Command::new(python.to_str().unwrap());
+    cmd.args(["-m", "pip", "freeze"]);
+    normalize_python_command_env(&mut cmd, app);
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to run pip freeze: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("pip freeze failed: {}", stderr));
+    }
+    Ok(parse_freeze_output(&String::from_utf8_lossy(&output.stdout)))
+}
+
+fn installed_package_versions_uv(app: &AppHandle, uv_bin: &PathBuf, python: &PathBuf) -> Result<HashMap<String, String>, String> {
+    let mut cmd = Command::new(uv_bin);
+    cmd.arg("pip").arg("freeze").arg("--python").arg(python);
+    normalize_python_command_env(&mut cmd, app);
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to run uv pip freeze: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("uv pip freeze failed: {}", stderr));
+    }
+    Ok(parse_freeze_output(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Reports the venv's installed package versions, preferring `pip freeze` but
+/// falling back to `uv pip freeze` when pip isn't present (e.g. a venv
+/// created by `uv venv` without `--seed`), so `verify_python_deps`,
+/// `doctor_report`, and the pip install fallback don't hard-fail on the
+/// uv-managed path.
+fn installed_package_versions(app: &AppHandle, python: &PathBuf) -> Result<HashMap<String, String>, String> {
+    match installed_package_versions_pip(app, python) {
+        Ok(installed) => Ok(installed),
+        Err(pip_err) => match find_uv(app) {
+            Some(uv_bin) => installed_package_versions_uv(app, &uv_bin, python).map_err(|uv_err| {
+                warn!(target: "python_setup", "pip freeze failed ({}) and uv pip freeze also failed ({})", pip_err, uv_err);
+                pip_err
+            }),
+            None => {
+                warn!(target: "python_setup", "pip freeze failed: {}", pip_err);
+                Err(pip_err)
+            }
+        },
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyStatus {
+    pub name: String,
+    pub required: String,
+    pub installed: Option<String>,
+    pub status: String,
+}
+
+pub fn dependency_statuses(app: &AppHandle, python: &PathBuf) -> Result<Vec<DependencyStatus>, String> {
+    let pyproject_path = resolve_pyproject_path(app)?;
+    if !pyproject_path.exists() {
+        return Err(format!("pyproject.toml not found at {}", pyproject_path.display()));
+    }
+
+    let pyproject = std::fs::read_to_string(&pyproject_path)
+        .map_err(|e| format!("Failed to read pyproject.toml: {}", e))?;
+    let deps = parse_pyproject_dependencies(&pyproject);
+    let installed = installed_package_versions(app, python)?;
+
+    Ok(deps
+        .into_iter()
+        .filter_map(|dep| parse_dependency_spec(&dep))
+        .map(|(name, required)| {
+            let installed_version = installed.get(&canonical_name(&name)).cloned();
+            let status = match &installed_version {
+                None => "missing",
+                Some(v) if satisfies_requirement(v, &required) => "satisfied",
+                Some(_) => "outdated",
+            };
+            DependencyStatus {
+                name,
+                required,
+                installed: installed_version,
+                status: status.to_string(),
+            }
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub async fn verify_python_deps(app: AppHandle) -> Result<Vec<DependencyStatus>, String> {
+    let python = get_venv_python(&app);
+    if !python.exists() {
+        return Err("Virtual environment not found. Please create it first.".to_string());
+    }
+    dependency_statuses(&app, &python)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DoctorReport {
+    pub python_version: Option<String>,
+    pub platform: String,
+    pub uv_version: Option<String>,
+    pub dependencies: Vec<DependencyStatus>,
+}
+
+fn uv_version(uv_bin: &PathBuf) -> Option<String> {
+    let output = Command::new(uv_bin).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8_lossy(&output.stdout);
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
         None
     } else {
-        Some(name.to_string())
+        Some(trimmed.to_string())
+    }
+}
+
+/// Reports the venv's resolved Python version, platform, uv version (if
+/// present), and per-dependency drift against `pyproject.toml`, so the UI can
+/// show an actionable diagnostics view and let a "Repair" action reinstall
+/// only the drifted packages.
+#[tauri::command]
+pub async fn doctor_report(app: AppHandle) -> Result<DoctorReport, String> {
+    let python = get_venv_python(&app);
+    if !python.exists() {
+        return Err("Virtual environment not found. Please create it first.".to_string());
     }
+
+    Ok(DoctorReport {
+        python_version: crate::diagnostics::python_version(&python),
+        platform: format!("{}-{}", std::env::consts::ARCH, std::env::consts::OS),
+        uv_version: find_uv(&app).and_then(|bin| uv_version(&bin)),
+        dependencies: dependency_statuses(&app, &python)?,
+    })
 }
 
 fn resolve_pyproject_path(app: &AppHandle) -> Result<PathBuf, String> {
@@ -104,16 +352,297 @@ pub fn pyproject_dependency_names(app: &AppHandle) -> Result<Vec<String>, String
     Ok(out)
 }
 
+fn uv_dir(app: &AppHandle) -> PathBuf {
+    get_keero_dir(app).join("uv")
+}
+
+fn uv_bin_path(app: &AppHandle) -> PathBuf {
+    let dir = uv_dir(app);
+    if cfg!(target_os = "windows") {
+        dir.join("uv.exe")
+    } else {
+        dir.join("uv")
+    }
+}
+
+fn which_uv() -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    let exe_name = if cfg!(target_os = "windows") { "uv.exe" } else { "uv" };
+    for dir in std::env::split_paths(&path) {
+        let candidate = dir.join(exe_name);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+    }
+    None
+}
+
+fn find_uv(app: &AppHandle) -> Option<PathBuf> {
+    let managed = uv_bin_path(app);
+    if managed.exists() {
+        return Some(managed);
+    }
+    which_uv()
+}
+
+/// Installs the `uv` binary into `<keero_dir>/uv` by running astral.sh's own
+/// install script, rather than bundling a per-platform binary.
+fn download_uv(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = uv_dir(app);
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create uv dir: {}", e))?;
+
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("powershell");
+        cmd.args([
+            "-ExecutionPolicy",
+            "ByPass",
+            "-c",
+            "irm https://astral.sh/uv/install.ps1 | iex",
+        ]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg("curl -LsSf https://astral.sh/uv/install.sh | sh");
+        cmd
+    };
+    cmd.env("UV_INSTALL_DIR", &dir).env("UV_UNMANAGED_INSTALL", &dir);
+
+    info!(target: "python_setup", "Downloading uv into {:?}", dir);
+    let output = cmd.output().map_err(|e| format!("Failed to download uv: {}", e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        warn!(target: "python_setup", "uv download failed: {}", stderr);
+        return Err(format!("Failed to download uv: {}", stderr));
+    }
+
+    let bin = uv_bin_path(app);
+    if !bin.exists() {
+        return Err(format!("uv installer did not produce a binary at {}", bin.display()));
+    }
+    Ok(bin)
+}
+
+/// Resolves the `uv` binary to use, honoring `PIXM8_UV_ENABLED` (set to "0"
+/// or "false" to force the legacy venv/pip path). Defaults to "on": uses an
+/// already-present `uv`, or tries to fetch one, before falling back to pip.
+fn uv_enabled(app: &AppHandle) -> Option<PathBuf> {
+    if matches!(std::env::var("PIXM8_UV_ENABLED").as_deref(), Ok("0") | Ok("false")) {
+        return None;
+    }
+    find_uv(app).or_else(|| download_uv(app).ok())
+}
+
+fn uv_lock_path(app: &AppHandle) -> PathBuf {
+    get_venv_path(app).join("requirements.lock")
+}
+
+/// Staging file for the uv-compiled dependency set, with mlx-audio carved out
+/// (see `install_python_deps_uv`).
+fn uv_requirements_in_path(app: &AppHandle) -> PathBuf {
+    get_venv_path(app).join("requirements.in")
+}
+
+/// Where `uv pip compile` writes before the sync it feeds has succeeded; see
+/// `install_python_deps_uv`.
+fn uv_lock_tmp_path(app: &AppHandle) -> PathBuf {
+    get_venv_path(app).join("requirements.lock.tmp")
+}
+
+/// A uv-generated lockfile newer than `pyproject.toml` means nothing has
+/// changed since the last successful sync, so re-running setup is a no-op.
+fn uv_lock_is_fresh(app: &AppHandle) -> bool {
+    let Ok(pyproject_path) = resolve_pyproject_path(app) else {
+        return false;
+    };
+    let lock_path = uv_lock_path(app);
+    let (Ok(lock_meta), Ok(pyproject_meta)) = (fs::metadata(&lock_path), fs::metadata(&pyproject_path)) else {
+        return false;
+    };
+    match (lock_meta.modified(), pyproject_meta.modified()) {
+        (Ok(lock_time), Ok(pyproject_time)) => lock_time >= pyproject_time,
+        _ => false,
+    }
+}
+
+fn create_venv_with_uv(app: &AppHandle, uv_bin: &PathBuf, python_for_venv: &PathBuf, venv_path: &PathBuf) -> Result<(), String> {
+    let mut cmd = Command::new(uv_bin);
+    // --seed: uv venv is pip-less by default, but installed_package_versions,
+    // the pip fallback in install_python_deps, and anything else that shells
+    // out to `python -m pip` in this venv need pip/setuptools present.
+    cmd.arg("venv").arg(venv_path).arg("--seed").arg("--python").arg(python_for_venv);
+    normalize_python_command_env(&mut cmd, app);
+    info!(target: "python_setup", "Running {:?} venv {:?} --seed --python {:?}", uv_bin, venv_path, python_for_venv);
+    let output = cmd.output().map_err(|e| format!("Failed to run uv venv: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+    Ok(())
+}
+
+/// Creates the project's virtual environment, preferring `uv venv` (an order
+/// of magnitude faster on first launch) and falling back to `python -m venv`
+/// when uv is disabled, missing, or fails.
+pub fn create_venv(app: &AppHandle, python_for_venv: &PathBuf, venv_path: &PathBuf) -> Result<(), String> {
+    if let Some(uv_bin) = uv_enabled(app) {
+        match create_venv_with_uv(app, &uv_bin, python_for_venv, venv_path) {
+            Ok(()) => return Ok(()),
+            Err(e) => warn!(target: "python_setup", "uv venv failed ({}), falling back to python -m venv", e),
+        }
+    }
+
+    let mut cmd = Command::new(python_for_venv.to_str().unwrap());
+    cmd.arg("-m").arg("venv").arg("--clear").arg(venv_path);
+    normalize_python_command_env(&mut cmd, app);
+    let output = cmd
+        .output()
+        .map_err(|e| format!("Failed to create venv: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "Failed to create venv: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok(())
+}
+
+/// Compiles `pyproject.toml`'s dependencies (minus mlx-audio, see below) into
+/// a lockfile and syncs the venv to it via `uv pip compile`/`uv pip sync`,
+/// which is a no-op when the lock is already up to date. The lock is compiled
+/// to a temp path and only moved onto `lock_path` once sync (and the
+/// mlx-audio install) succeed, so a failed sync can't leave behind a lock
+/// newer than `pyproject.toml` that makes `uv_lock_is_fresh` mistake an
+/// under-installed venv for a complete one.
+fn install_python_deps_uv(app: &AppHandle, uv_bin: &PathBuf, venv_python: &PathBuf) -> Result<String, String> {
+    let pyproject_path = resolve_pyproject_path(app)?;
+    if !pyproject_path.exists() {
+        return Err(format!("pyproject.toml not found at {}", pyproject_path.display()));
+    }
+    let pyproject = std::fs::read_to_string(&pyproject_path)
+        .map_err(|e| format!("Failed to read pyproject.toml: {}", e))?;
+    let deps = parse_pyproject_dependencies(&pyproject);
+    if deps.is_empty() {
+        return Err("No dependencies found in pyproject.toml".to_string());
+    }
+
+    // Carve mlx-audio out of the compiled set, same as the pip path: it's
+    // installed separately with --no-deps to avoid resolver conflicts, so
+    // letting uv pull in its full dependency tree here would defeat that.
+    let mut mlx_audio_spec: Option<String> = None;
+    let mut rest: Vec<String> = Vec::new();
+    for dep in deps {
+        if dep.starts_with("mlx-audio") {
+            if mlx_audio_spec.is_some() {
+                return Err("Multiple mlx-audio entries found in pyproject.toml dependencies".to_string());
+            }
+            mlx_audio_spec = Some(dep);
+        } else {
+            rest.push(dep);
+        }
+    }
+
+    let requirements_in_path = uv_requirements_in_path(app);
+    fs::write(&requirements_in_path, rest.join("\n"))
+        .map_err(|e| format!("Failed to write {}: {}", requirements_in_path.display(), e))?;
+    let lock_tmp_path = uv_lock_tmp_path(app);
+    let lock_path = uv_lock_path(app);
+
+    let mut compile_cmd = Command::new(uv_bin);
+    compile_cmd
+        .arg("pip")
+        .arg("compile")
+        .arg("--quiet")
+        .arg(&requirements_in_path)
+        .arg("-o")
+        .arg(&lock_tmp_path);
+    normalize_python_command_env(&mut compile_cmd, app);
+    info!(target: "python_setup", "Running {:?} pip compile {:?} -o {:?}", uv_bin, requirements_in_path, lock_tmp_path);
+    let output = compile_cmd
+        .output()
+        .map_err(|e| format!("Failed to compile uv lockfile: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    let mut sync_cmd = Command::new(uv_bin);
+    sync_cmd
+        .arg("pip")
+        .arg("sync")
+        .arg("--python")
+        .arg(venv_python)
+        .arg(&lock_tmp_path);
+    normalize_python_command_env(&mut sync_cmd, app);
+    info!(target: "python_setup", "Running {:?} pip sync --python {:?} {:?}", uv_bin, venv_python, lock_tmp_path);
+    let output = sync_cmd
+        .output()
+        .map_err(|e| format!("Failed to sync uv lockfile: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    if let Some(spec) = mlx_audio_spec {
+        let mut cmd = Command::new(uv_bin);
+        cmd.arg("pip")
+            .arg("install")
+            .arg("--python")
+            .arg(venv_python)
+            .arg("--no-deps")
+            .arg(&spec);
+        normalize_python_command_env(&mut cmd, app);
+        info!(target: "python_setup", "Running {:?} pip install --python {:?} --no-deps {}", uv_bin, venv_python, spec);
+        let output = cmd
+            .output()
+            .map_err(|e| format!("Failed to install mlx-audio via uv: {}", e))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            error!(target: "python_setup", "uv mlx-audio install exited with {}: {}", output.status, stderr);
+            return Err(format!("Failed to install mlx-audio via uv: {}", stderr));
+        }
+    }
+
+    // Only now that the venv actually matches it do we let the lock become
+    // the one `uv_lock_is_fresh` checks against.
+    fs::rename(&lock_tmp_path, &lock_path)
+        .map_err(|e| format!("Failed to finalize uv lockfile: {}", e))?;
+
+    Ok("Dependencies installed successfully via uv".to_string())
+}
+
+/// Reports whether the venv's dependencies match `pyproject.toml`, preferring
+/// the cheap uv-lock freshness check before falling back to a per-package
+/// `pip freeze` comparison (covers venvs set up by either the pip or the uv
+/// install path).
+pub fn deps_satisfied(app: &AppHandle, python: &PathBuf) -> bool {
+    if uv_lock_is_fresh(app) {
+        return true;
+    }
+    match dependency_statuses(app, python) {
+        Ok(statuses) => !statuses.is_empty() && statuses.iter().all(|s| s.status == "satisfied"),
+        Err(_) => false,
+    }
+}
+
 pub fn install_python_deps(app: &AppHandle, pip_path: PathBuf) -> Result<String, String> {
     if !pip_path.exists() {
         return Err("Virtual environment not found. Please create it first.".to_string());
     }
 
-    let _ = Command::new(pip_path.to_str().unwrap())
-        .arg("install")
-        .arg("--upgrade")
-        .arg("pip")
-        .output();
+    if uv_lock_is_fresh(app) {
+        info!(target: "python_setup", "uv lockfile is up to date with pyproject.toml; skipping install");
+        return Ok("Dependencies already satisfied".to_string());
+    }
+
+    if let Some(uv_bin) = uv_enabled(app) {
+        let venv_python = get_venv_python(app);
+        match install_python_deps_uv(app, &uv_bin, &venv_python) {
+            Ok(msg) => return Ok(msg),
+            Err(e) => warn!(target: "python_setup", "uv-based dependency install failed ({}), falling back to pip", e),
+        }
+    }
+
+    let mut upgrade_pip_cmd = Command::new(pip_path.to_str().unwrap());
+    upgrade_pip_cmd.arg("install").arg("--upgrade").arg("pip");
+    normalize_python_command_env(&mut upgrade_pip_cmd, app);
+    let _ = upgrade_pip_cmd.output();
 
     let pyproject_path = resolve_pyproject_path(app)?;
     if !pyproject_path.exists() {
@@ -144,41 +673,71 @@ pub fn install_python_deps(app: &AppHandle, pip_path: PathBuf) -> Result<String,
     }
 
     if let Some(spec) = mlx_audio_spec {
-        let output = Command::new(pip_path.to_str().unwrap())
-            .args([
-                "install",
-                "--upgrade",
-                "--force-reinstall",
-                "--no-deps",
-                &spec,
-            ])
+        let mut cmd = Command::new(pip_path.to_str().unwrap());
+        cmd.args(["install", "--upgrade", "--force-reinstall", "--no-deps", &spec]);
+        normalize_python_command_env(&mut cmd, app);
+        info!(target: "python_setup", "Running {:?} install --upgrade --force-reinstall --no-deps {}", pip_path, spec);
+        let output = cmd
             .output()
             .map_err(|e| format!("Failed to install mlx-audio: {}", e))?;
 
         if !output.status.success() {
-            return Err(format!(
-                "Failed to install mlx-audio: {}",
-                String::from_utf8_lossy(&output.stderr)
-            ));
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            error!(
+                target: "python_setup",
+                "mlx-audio install exited with {}: {}", output.status, stderr
+            );
+            return Err(format!("Failed to install mlx-audio: {}", stderr));
         }
     }
 
+    // Only (re)install what's missing or version-mismatched; packages that
+    // already satisfy their pyproject spec are left alone instead of being
+    // force-reinstalled on every setup run.
+    let venv_python = get_venv_python(app);
+    let installed = installed_package_versions(app, &venv_python).unwrap_or_default();
+    let to_install: Vec<String> = rest
+        .into_iter()
+        .filter(|dep| match parse_dependency_spec(dep) {
+            Some((name, required)) => match installed.get(&canonical_name(&name)) {
+                Some(version) => !satisfies_requirement(version, &required),
+                None => true,
+            },
+            None => true,
+        })
+        .collect();
+
+    if to_install.is_empty() {
+        info!(target: "python_setup", "All pyproject dependencies already satisfied; skipping install");
+        return Ok("Dependencies already satisfied".to_string());
+    }
+
     let mut cmd = Command::new(pip_path.to_str().unwrap());
     cmd.arg("install").arg("--upgrade").arg("--force-reinstall");
-    for dep in rest {
+    for dep in &to_install {
         cmd.arg(dep);
     }
+    normalize_python_command_env(&mut cmd, app);
 
+    info!(
+        target: "python_setup",
+        "Running {:?} install --upgrade --force-reinstall {}",
+        pip_path,
+        to_install.join(" ")
+    );
     let output = cmd
         .output()
         .map_err(|e| format!("Failed to install deps: {}", e))?;
 
     if !output.status.success() {
-        return Err(format!(
-            "Failed to install dependencies: {}",
-            String::from_utf8_lossy(&output.stderr)
-        ));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        error!(
+            target: "python_setup",
+            "Dependency install exited with {}: {}", output.status, stderr
+        );
+        return Err(format!("Failed to install dependencies: {}", stderr));
     }
 
+    info!(target: "python_setup", "Installed {} package(s) successfully", to_install.len());
     Ok("Dependencies installed successfully".to_string())
 }
@@ -1,6 +1,8 @@
 use std::fs;
+use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
 
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Emitter};
@@ -23,6 +25,9 @@ fn pip_has_package(python: &PathBuf, name: &str) -> bool {
 }
 
 fn deps_installed_from_pyproject(app: &AppHandle, python: &PathBuf) -> bool {
+    if python_setup::deps_satisfied(app, python) {
+        return true;
+    }
     let deps = python_setup::pyproject_dependency_names(app).unwrap_or_default();
     if deps.is_empty() {
         return false;
@@ -130,20 +135,7 @@ pub async fn create_python_venv(app: AppHandle) -> Result<String, String> {
     app.emit("setup-progress", "Creating Python virtual environment...")
         .ok();
 
-    let output = Command::new(python_for_venv.to_str().unwrap())
-        .arg("-m")
-        .arg("venv")
-        .arg("--clear")
-        .arg(&venv_path)
-        .output()
-        .map_err(|e| format!("Failed to create venv: {}", e))?;
-
-    if !output.status.success() {
-        return Err(format!(
-            "Failed to create venv: {}",
-            String::from_utf8_lossy(&output.stderr)
-        ));
-    }
+    python_setup::create_venv(&app, &python_for_venv, &venv_path)?;
 
     Ok(venv_path.to_string_lossy().to_string())
 }
@@ -182,13 +174,196 @@ pub async fn is_first_launch(app: AppHandle) -> Result<bool, String> {
     Ok(!marker_file.exists() || !venv_python.exists())
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PackageManager {
+    Winget,
+    Choco,
+    Scoop,
+    Brew,
+    Apt,
+    Dnf,
+    Pacman,
+    Zypper,
+}
+
+impl PackageManager {
+    fn label(self) -> &'static str {
+        match self {
+            PackageManager::Winget => "winget",
+            PackageManager::Choco => "choco",
+            PackageManager::Scoop => "scoop",
+            PackageManager::Brew => "brew",
+            PackageManager::Apt => "apt",
+            PackageManager::Dnf => "dnf",
+            PackageManager::Pacman => "pacman",
+            PackageManager::Zypper => "zypper",
+        }
+    }
+}
+
+fn which(name: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    for dir in std::env::split_paths(&path) {
+        let candidate = dir.join(name);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        if cfg!(target_os = "windows") {
+            let candidate_exe = dir.join(format!("{name}.exe"));
+            if candidate_exe.is_file() {
+                return Some(candidate_exe);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(target_os = "windows")]
+fn detect_package_manager() -> Option<PackageManager> {
+    if which("winget").is_some() {
+        Some(PackageManager::Winget)
+    } else if which("choco").is_some() {
+        Some(PackageManager::Choco)
+    } else if which("scoop").is_some() {
+        Some(PackageManager::Scoop)
+    } else {
+        None
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn detect_package_manager() -> Option<PackageManager> {
+    which("brew").map(|_| PackageManager::Brew)
+}
+
+#[cfg(target_os = "linux")]
+fn detect_package_manager() -> Option<PackageManager> {
+    if which("apt-get").is_some() {
+        Some(PackageManager::Apt)
+    } else if which("dnf").is_some() {
+        Some(PackageManager::Dnf)
+    } else if which("pacman").is_some() {
+        Some(PackageManager::Pacman)
+    } else if which("zypper").is_some() {
+        Some(PackageManager::Zypper)
+    } else {
+        None
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+fn detect_package_manager() -> Option<PackageManager> {
+    None
+}
+
+/// Wraps a Linux system package manager invocation with `pkexec` (preferred,
+/// gives a native GUI prompt) or `sudo` for the privilege escalation it needs.
+fn privileged_command(program: &str, args: &[&str]) -> Command {
+    let escalator = if which("pkexec").is_some() { "pkexec" } else { "sudo" };
+    let mut cmd = Command::new(escalator);
+    cmd.arg(program).args(args);
+    cmd
+}
+
+fn build_install_command(manager: PackageManager) -> Command {
+    match manager {
+        PackageManager::Winget => {
+            let mut cmd = Command::new("winget");
+            cmd.args([
+                "install",
+                "--id",
+                "UB-Mannheim.TesseractOCR",
+                "-e",
+                "--accept-source-agreements",
+                "--accept-package-agreements",
+            ]);
+            cmd
+        }
+        PackageManager::Choco => {
+            let mut cmd = Command::new("choco");
+            cmd.args(["install", "tesseract", "-y"]);
+            cmd
+        }
+        PackageManager::Scoop => {
+            let mut cmd = Command::new("scoop");
+            cmd.args(["install", "tesseract"]);
+            cmd
+        }
+        PackageManager::Brew => {
+            let mut cmd = Command::new("brew");
+            cmd.args(["install", "tesseract"]);
+            cmd
+        }
+        PackageManager::Apt => privileged_command("apt-get", &["install", "-y", "tesseract-ocr"]),
+        PackageManager::Dnf => privileged_command("dnf", &["install", "-y", "tesseract"]),
+        PackageManager::Pacman => privileged_command("pacman", &["-S", "--noconfirm", "tesseract"]),
+        PackageManager::Zypper => privileged_command("zypper", &["install", "-y", "tesseract-ocr"]),
+    }
+}
+
+/// Runs `cmd` to completion, forwarding each stdout/stderr line as a
+/// `setup-progress` event so the UI shows real install progress, and
+/// returning the last few stderr lines on failure.
+fn stream_command_progress(app: &AppHandle, mut cmd: Command) -> Result<(), String> {
+    let program = format!("{:?}", cmd);
+    let mut child = cmd
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to start {}: {}", program, e))?;
+
+    let stderr_tail = Arc::new(Mutex::new(Vec::<String>::new()));
+    let mut readers = Vec::new();
+
+    if let Some(stdout) = child.stdout.take() {
+        let app = app.clone();
+        readers.push(std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                let _ = app.emit("setup-progress", line);
+            }
+        }));
+    }
+
+    if let Some(stderr) = child.stderr.take() {
+        let app = app.clone();
+        let tail = stderr_tail.clone();
+        readers.push(std::thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                if let Ok(mut tail) = tail.lock() {
+                    tail.push(line.clone());
+                    if tail.len() > 20 {
+                        tail.remove(0);
+                    }
+                }
+                let _ = app.emit("setup-progress", line);
+            }
+        }));
+    }
+
+    let status = child
+        .wait()
+        .map_err(|e| format!("Failed to wait for {}: {}", program, e))?;
+    for reader in readers {
+        let _ = reader.join();
+    }
+
+    if !status.success() {
+        let tail = stderr_tail.lock().map(|t| t.join("\n")).unwrap_or_default();
+        return Err(format!("{} exited with {}: {}", program, status, tail));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct TesseractStatus {
     pub installed: bool,
     pub path: Option<String>,
     pub manual_download_url: String,
-    /// True on macOS (can run `brew install tesseract`).
+    /// True when a supported package manager was detected for this platform.
     pub can_auto_install: bool,
+    /// Label of the detected package manager (e.g. "winget"), if any, so the
+    /// UI can show "Install via winget" instead of a generic button.
+    pub detected_package_manager: Option<String>,
 }
 
 #[tauri::command]
@@ -201,58 +376,31 @@ pub async fn tesseract_status(app: AppHandle) -> Result<TesseractStatus, String>
         "macos" => "https://tesseract-ocr.github.io/tessdoc/Installation.html#macos".to_string(),
         _ => "https://tesseract-ocr.github.io/tessdoc/Installation.html".to_string(),
     };
-    let can_auto_install = cfg!(target_os = "macos");
+    let detected = detect_package_manager();
     Ok(TesseractStatus {
         installed,
         path: path_str,
         manual_download_url,
-        can_auto_install,
+        can_auto_install: detected.is_some(),
+        detected_package_manager: detected.map(|m| m.label().to_string()),
     })
 }
 
-/// Install Tesseract. On macOS tries `brew install tesseract` if Homebrew is available.
-/// On Windows/Linux returns an error; user should install manually.
+/// Installs Tesseract via whatever supported package manager was detected for
+/// this platform, streaming its output as `setup-progress` events. Returns an
+/// error (with the manager's output) when none is available or the install
+/// fails, so the caller can fall back to the manual-download URL.
 #[tauri::command]
-pub async fn tesseract_install(_app: AppHandle) -> Result<(), String> {
-    #[cfg(target_os = "macos")]
-    {
-        let brew = which_brew();
-        let brew = match brew {
-            Some(p) => p,
-            None => {
-                return Err("Homebrew not found. Install it from https://brew.sh or install Tesseract manually.".to_string());
-            }
-        };
-        let output = Command::new(brew)
-            .args(["install", "tesseract"])
-            .output()
-            .map_err(|e| format!("Failed to run Homebrew: {}", e))?;
-        if output.status.success() {
-            return Ok(());
-        }
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        Err(format!(
-            "Homebrew install failed: {} {}",
-            stdout.trim(),
-            stderr.trim()
-        ))
-    }
-    #[cfg(not(target_os = "macos"))]
-    {
-        let _ = _app;
-        Err("Automatic install is only available on macOS (via Homebrew). Please use the install instructions link below.".to_string())
-    }
-}
+pub async fn tesseract_install(app: AppHandle) -> Result<(), String> {
+    let manager = detect_package_manager().ok_or_else(|| {
+        "No supported package manager found. Please install Tesseract manually.".to_string()
+    })?;
 
-#[cfg(target_os = "macos")]
-fn which_brew() -> Option<PathBuf> {
-    let path = std::env::var_os("PATH")?;
-    for dir in std::env::split_paths(&path) {
-        let brew = dir.join("brew");
-        if brew.is_file() {
-            return Some(brew);
-        }
-    }
-    None
+    app.emit(
+        "setup-progress",
+        format!("Installing Tesseract via {}...", manager.label()),
+    )
+    .ok();
+
+    stream_command_progress(&app, build_install_command(manager))
 }